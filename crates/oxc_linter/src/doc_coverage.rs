@@ -0,0 +1,155 @@
+//! Documentation-coverage reporting for the `jsdoc` plugin.
+//!
+//! Rather than emitting one diagnostic per undocumented symbol (as
+//! [`RequirePublicDoc`](crate::rules::jsdoc::require_public_doc::RequirePublicDoc) does), this
+//! counts how many eligible public exports carry a JSDoc block versus how many don't, per file
+//! and in total, and formats the totals into a summary table, so teams can track documentation
+//! debt over time without failing the build.
+//!
+//! [`LintContext::doc_coverage`] computes one file's totals; [`render_table`] formats several of
+//! them (keyed by file path) into the table a CLI would print. Hooking a `--report-doc-coverage`
+//! flag up to these two calls is CLI-side work that lives in `oxc_cli`, outside this crate, and
+//! is left as follow-up.
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+use std::fmt::Write as _;
+
+use oxc_span::Span;
+
+use crate::{context::LintContext, rules::jsdoc::doc_utils::{has_any_attached_jsdoc, local_exported_symbols}};
+
+/// Which kinds of exported declarations count towards doc coverage. Defaults to all of them.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocCoverageEligibility {
+    pub functions: bool,
+    pub classes: bool,
+    pub variables: bool,
+    pub type_aliases: bool,
+}
+
+impl Default for DocCoverageEligibility {
+    fn default() -> Self {
+        Self { functions: true, classes: true, variables: true, type_aliases: true }
+    }
+}
+
+/// Coverage totals for a single file. Keeps the span of each undocumented export so a future
+/// consumer (a CLI `--fix` suggestion, an editor gutter icon) can point at it directly instead
+/// of re-resolving the name.
+#[derive(Debug, Default, Clone)]
+pub struct DocCoverageReport {
+    pub documented: u32,
+    pub undocumented: Vec<(String, Span)>,
+}
+
+impl DocCoverageReport {
+    pub fn total(&self) -> u32 {
+        self.documented + self.undocumented.len() as u32
+    }
+
+    /// Percentage of eligible exports that are documented, in `[0.0, 100.0]`. `100.0` when there
+    /// are no eligible exports, matching rustdoc's `calculate_doc_coverage` convention of not
+    /// penalizing a file that simply has nothing to document.
+    pub fn percentage(&self) -> f64 {
+        let total = self.total();
+        if total == 0 { 100.0 } else { f64::from(self.documented) / f64::from(total) * 100.0 }
+    }
+}
+
+impl<'a> LintContext<'a> {
+    /// Calculates documentation coverage for locally exported symbols in the current module,
+    /// per the eligibility set in `eligibility`. Mirrors rustdoc's `calculate_doc_coverage`,
+    /// reusing the same export-collection and JSDoc-attachment logic as `RequirePublicDoc`.
+    pub fn doc_coverage(&self, eligibility: &DocCoverageEligibility) -> DocCoverageReport {
+        let mut report = DocCoverageReport::default();
+        let mut seen: FxHashSet<oxc_semantic::SymbolId> = FxHashSet::default();
+
+        for symbol in local_exported_symbols(self) {
+            if !seen.insert(symbol.symbol_id) {
+                continue;
+            }
+
+            let decl_id = self.scoping().symbol_declaration(symbol.symbol_id);
+            let decl_node = self.nodes().get_node(decl_id);
+
+            if !is_eligible(decl_node, eligibility) {
+                continue;
+            }
+
+            if has_any_attached_jsdoc(decl_node, self) {
+                report.documented += 1;
+            } else {
+                report.undocumented.push((symbol.name.to_string(), symbol.export_span));
+            }
+        }
+
+        report
+    }
+}
+
+fn is_eligible(node: &crate::AstNode, eligibility: &DocCoverageEligibility) -> bool {
+    use oxc_ast::AstKind;
+
+    match node.kind() {
+        AstKind::Function(_) => eligibility.functions,
+        AstKind::Class(_) => eligibility.classes,
+        AstKind::VariableDeclarator(_) => eligibility.variables,
+        AstKind::TSTypeAliasDeclaration(_) => eligibility.type_aliases,
+        // Unrecognized declaration shapes default to counted, same as `RequirePublicDoc`.
+        _ => true,
+    }
+}
+
+/// Formats per-file coverage reports into the summary table a CLI would print, one row per file
+/// plus a trailing total row, e.g.:
+///
+/// ```text
+/// src/foo.ts   80.0% (4/5)
+/// src/bar.ts  100.0% (2/2)
+/// total        85.7% (6/7)
+/// ```
+pub fn render_table(reports: &[(String, DocCoverageReport)]) -> String {
+    let mut out = String::new();
+    let name_width = reports.iter().map(|(name, _)| name.len()).max().unwrap_or(0).max("total".len());
+
+    let mut total_documented = 0;
+    let mut total_count = 0;
+
+    for (name, report) in reports {
+        let _ = writeln!(
+            out,
+            "{name:name_width$}  {:>5.1}% ({}/{})",
+            report.percentage(),
+            report.documented,
+            report.total()
+        );
+        total_documented += report.documented;
+        total_count += report.total();
+    }
+
+    let total_percentage = if total_count == 0 { 100.0 } else { f64::from(total_documented) / f64::from(total_count) * 100.0 };
+    let _ = write!(out, "{:name_width$}  {total_percentage:>5.1}% ({total_documented}/{total_count})", "total");
+
+    out
+}
+
+#[test]
+fn test_render_table() {
+    let reports = vec![
+        (
+            "src/foo.ts".to_string(),
+            DocCoverageReport { documented: 4, undocumented: vec![("bar".to_string(), Span::default())] },
+        ),
+        ("src/baz.ts".to_string(), DocCoverageReport { documented: 2, undocumented: vec![] }),
+    ];
+
+    let table = render_table(&reports);
+    assert!(table.contains("src/foo.ts"));
+    assert!(table.contains("80.0% (4/5)"));
+    assert!(table.contains("src/baz.ts"));
+    assert!(table.contains("100.0% (2/2)"));
+    assert!(table.contains("total"));
+    assert!(table.contains("85.7% (6/7)"));
+}
@@ -0,0 +1,161 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, module_record::{ExportLocalName, ImportImportName}, rule::Rule};
+
+fn broken_doc_link_diagnostic(span: Span, target: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Broken doc link to `{target}`."))
+        .with_help("Check that the name is spelled correctly and is a binding, import, or export visible from this module.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckDocLinks;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Validates `{@link Target}` / `{@linkcode Target}` references in JSDoc comments, warning
+    /// when `Target` cannot be resolved against the module's local bindings, imports, or a
+    /// recognized global.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A broken doc link silently rots: the name it pointed at was renamed or removed, and
+    /// nothing else catches it.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /** See {@link Helper} for details. */
+    /// export function quux() {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function Helper() {}
+    /// /** See {@link Helper} for details. */
+    /// export function quux() {}
+    /// ```
+    CheckDocLinks,
+    jsdoc,
+    pedantic
+);
+
+impl Rule for CheckDocLinks {
+    fn run_once(&self, ctx: &LintContext) {
+        for node in ctx.nodes() {
+            let Some(jsdocs) = ctx.jsdoc().get_all_by_node(ctx.nodes(), node) else { continue };
+
+            for jsdoc in jsdocs {
+                let comment = jsdoc.comment();
+                for (target, target_span) in find_link_tokens(&comment, jsdoc.span()) {
+                    let base = target.split(['#', '.']).next().unwrap_or(&target);
+                    if !is_resolvable(base, ctx) {
+                        ctx.diagnostic(broken_doc_link_diagnostic(target_span, &target));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Scans `comment` for `{@link Target}` and `{@linkcode Target}` tokens, returning each target
+/// string together with its span within the overall JSDoc comment. Bare URLs are left to the
+/// `require-linked-urls` rule.
+fn find_link_tokens(comment: &str, comment_span: Span) -> Vec<(String, Span)> {
+    let mut out = Vec::new();
+    let mut i = 0;
+
+    while let Some(rel_open) = comment[i..].find('{') {
+        let open = i + rel_open;
+        let Some(rel_close) = comment[open..].find('}') else { break };
+        let close = open + rel_close;
+        let inner = &comment[open + 1..close];
+
+        let rest = inner
+            .strip_prefix("@linkcode")
+            .or_else(|| inner.strip_prefix("@link"))
+            .map(str::trim_start);
+
+        if let Some(rest) = rest {
+            // `{@link Target|display text}` — only the target resolves.
+            let target = rest.split('|').next().unwrap_or(rest).trim();
+            if !target.is_empty() && !target.starts_with("http://") && !target.starts_with("https://") {
+                let target_start = open + 1 + (inner.len() - rest.len()) + rest.find(target).unwrap_or(0);
+                let span = Span::new(
+                    comment_span.start + target_start as u32,
+                    comment_span.start + (target_start + target.len()) as u32,
+                );
+                out.push((target.to_string(), span));
+            }
+        }
+
+        i = close + 1;
+    }
+
+    out
+}
+
+fn is_resolvable(name: &str, ctx: &LintContext) -> bool {
+    if ctx.scoping().get_root_binding(name).is_some() {
+        return true;
+    }
+
+    let module = ctx.module_record();
+    if module.local_export_entries.iter().any(|e| matches!(&e.local_name, ExportLocalName::Name(n) | ExportLocalName::Default(n) if n.name.as_str() == name))
+    {
+        return true;
+    }
+
+    for entries in module.import_entries.iter() {
+        if entries.local_name.name.as_str() == name {
+            return true;
+        }
+        if let ImportImportName::Name(imported) = &entries.import_name {
+            if imported.name.as_str() == name {
+                return true;
+            }
+        }
+    }
+
+    is_recognized_global(name)
+}
+
+fn is_recognized_global(name: &str) -> bool {
+    matches!(
+        name,
+        "Array" | "Boolean" | "Date" | "Error" | "Function" | "JSON" | "Map" | "Math" | "Number"
+            | "Object" | "Promise" | "RegExp" | "Set" | "String" | "Symbol" | "WeakMap" | "WeakSet"
+            | "globalThis" | "undefined" | "null"
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "function Helper() {}\n/** See {@link Helper}. */\nexport function quux() {}",
+            None,
+            None,
+        ),
+        (
+            "/** See {@link Array#map}. */\nexport function quux() {}",
+            None,
+            None,
+        ),
+        (
+            "/** See {@link https://example.com}. */\nexport function quux() {}",
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![("/** See {@link Missing}. */\nexport function quux() {}", None, None)];
+
+    Tester::new(CheckDocLinks::NAME, CheckDocLinks::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,185 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn check_example_syntax_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`@example` code block does not parse as valid JavaScript/TypeScript.")
+        .with_help("Fix the syntax error in the example so it can be copy-pasted and run as-is.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckExampleSyntax;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Parses the code inside JSDoc `@example` tags and reports a diagnostic when the
+    /// snippet fails to parse.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An `@example` that doesn't actually parse is misleading: readers copy it into their
+    /// own code and hit a syntax error, or worse, silently believe broken code is correct.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @example
+    ///  * ```js
+    ///  * const x = ;
+    ///  * ```
+    ///  */
+    /// export function quux() {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /**
+    ///  * @example
+    ///  * ```js
+    ///  * const x = quux();
+    ///  * ```
+    ///  */
+    /// export function quux() {}
+    /// ```
+    CheckExampleSyntax,
+    jsdoc,
+    pedantic
+);
+
+impl Rule for CheckExampleSyntax {
+    fn run_once(&self, ctx: &LintContext) {
+        for node in ctx.nodes() {
+            let Some(jsdocs) = ctx.jsdoc().get_all_by_node(ctx.nodes(), node) else { continue };
+
+            for jsdoc in jsdocs {
+                for example in jsdoc.tags().iter().filter(|tag| tag.kind() == "example") {
+                    let Some((snippet, source_type, snippet_span)) =
+                        extract_example_snippet(example.comment().as_str(), example.span(), ctx.source_type())
+                    else {
+                        continue;
+                    };
+
+                    if snippet.trim().is_empty() {
+                        continue;
+                    }
+
+                    if let Some(error_span) = first_parse_error_span(&snippet, source_type) {
+                        let offset = snippet_span.start + error_span;
+                        ctx.diagnostic(check_example_syntax_diagnostic(Span::new(offset, offset)));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Strips a leading fence (` ```js `, ` ```ts `, ` ```jsx `, ` ```tsx `, plain indented code) from
+/// an `@example` comment body, returning the code text, the `SourceType` to parse it with, and
+/// the span of the code text within the overall JSDoc comment. Fences tagged with a non-JS
+/// language (e.g. ` ```json `, ` ```html `) are skipped entirely.
+fn extract_example_snippet(
+    comment: &str,
+    comment_span: Span,
+    default_source_type: SourceType,
+) -> Option<(String, SourceType, Span)> {
+    const JS_FENCES: &[(&str, bool, bool)] =
+        &[("js", false, false), ("javascript", false, false), ("jsx", true, false), ("ts", false, true), ("tsx", true, true)];
+
+    if let Some(fence_start) = comment.find("```") {
+        let after_fence = &comment[fence_start + 3..];
+        let lang_end = after_fence.find(['\n', '\r']).unwrap_or(after_fence.len());
+        let lang = after_fence[..lang_end].trim();
+
+        let source_type = if lang.is_empty() {
+            // Unlabeled fence: assume the file's own source type.
+            default_source_type
+        } else if let Some((_, jsx, ts)) = JS_FENCES.iter().find(|(name, ..)| *name == lang) {
+            SourceType::default().with_jsx(*jsx).with_typescript(*ts)
+        } else {
+            // Non-JS fence (json, html, bash, ...): not our concern.
+            return None;
+        };
+
+        let body_start = fence_start + 3 + lang_end;
+        let body = &after_fence[lang_end..];
+        let end = body.find("```").unwrap_or(body.len());
+        let code = body[..end].to_string();
+        let span = Span::new(comment_span.start + body_start as u32, comment_span.start + (body_start + end) as u32);
+        return Some((code, source_type, span));
+    }
+
+    // No fence: treat the whole comment body (minus the `@example` tag name) as the snippet.
+    Some((comment.to_string(), default_source_type, comment_span))
+}
+
+/// Tries to parse `snippet` as a full program; if that fails, retries as a single expression
+/// statement so that expression-only examples (e.g. `foo(1, 2)`) are accepted. Returns the
+/// offset of the first parse error within `snippet`, or `None` if either attempt succeeds.
+fn first_parse_error_span(snippet: &str, source_type: SourceType) -> Option<u32> {
+    let allocator = oxc_allocator::Allocator::default();
+    let result = Parser::new(&allocator, snippet, source_type).parse();
+    if result.errors.is_empty() {
+        return None;
+    }
+
+    let as_expression = format!("({snippet})");
+    let retry = Parser::new(&allocator, &as_expression, source_type).parse();
+    if retry.errors.is_empty() {
+        return None;
+    }
+
+    // Dedupe multiple cascading errors from one block into a single diagnostic by only
+    // reporting the first one.
+    result.errors.first().map(|error| error.labels.first().map_or(0, |label| label.offset() as u32))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "/**\n * @example\n * ```js\n * const x = 1;\n * ```\n */\nexport function quux() {}",
+            None,
+            None,
+        ),
+        (
+            "/**\n * @example\n * ```json\n * { \"a\": , }\n * ```\n */\nexport function quux() {}",
+            None,
+            None,
+        ),
+        (
+            "/**\n * @example\n * quux(1, 2)\n * ```\n */\nexport function quux() {}",
+            None,
+            None,
+        ),
+        (
+            "/**\n * @example\n * ```\n * const x = 1;\n * ```\n */\nexport function quux() {}",
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "/**\n * @example\n * ```js\n * const x = ;\n * ```\n */\nexport function quux() {}",
+            None,
+            None,
+        ),
+        (
+            "/**\n * @example\n * ```\n * const x = ;\n * ```\n */\nexport function quux() {}",
+            None,
+            None,
+        ),
+    ];
+
+    Tester::new(CheckExampleSyntax::NAME, CheckExampleSyntax::PLUGIN, pass, fail).test_and_snapshot();
+}
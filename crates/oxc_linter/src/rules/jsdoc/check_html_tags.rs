@@ -0,0 +1,188 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn unclosed_html_tag_diagnostic(span: Span, tag: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unclosed HTML tag `<{tag}>` in JSDoc comment."))
+        .with_help(format!("Add a matching `</{tag}>`, or remove the opening tag."))
+        .with_label(span)
+}
+
+fn stray_angle_bracket_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Stray `<` in JSDoc comment that doesn't open a recognized HTML tag.")
+        .with_help("Escape it as `&lt;` if it isn't meant to start a tag.")
+        .with_label(span)
+}
+
+/// Elements that don't require (and can't have) a closing tag.
+const VOID_ELEMENTS: &[&str] =
+    &["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr"];
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckHtmlTags;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Detects unbalanced or unclosed HTML tags in JSDoc comment text, e.g. `<code>` without a
+    /// matching `</code>`, or a stray `<` that doesn't open a recognized tag.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unbalanced HTML in a doc comment renders incorrectly (or not at all) in tooling that
+    /// treats JSDoc text as HTML, and usually signals a typo.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /** Returns <code>true</code if the value is valid. */
+    /// export function quux() {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /** Returns <code>true</code> if the value is valid. */
+    /// export function quux() {}
+    /// ```
+    CheckHtmlTags,
+    jsdoc,
+    pedantic
+);
+
+impl Rule for CheckHtmlTags {
+    fn run_once(&self, ctx: &LintContext) {
+        for node in ctx.nodes() {
+            let Some(jsdocs) = ctx.jsdoc().get_all_by_node(ctx.nodes(), node) else { continue };
+
+            for jsdoc in jsdocs {
+                let comment = jsdoc.comment();
+                for diagnostic in scan_html_tags(&comment, jsdoc.span()) {
+                    ctx.diagnostic(diagnostic);
+                }
+            }
+        }
+    }
+}
+
+enum Token {
+    Open { name: String, span: Span },
+    Close { name: String },
+    Stray { span: Span },
+}
+
+/// Scans `comment` for HTML tags, maintaining a stack of unmatched opening tags, and returns a
+/// diagnostic for each tag left unclosed at the end plus each stray `<` that never formed a
+/// recognized tag. Tags inside fenced code blocks (```` ``` ````) are ignored, since those are
+/// code samples, not comment prose.
+fn scan_html_tags(comment: &str, comment_span: Span) -> Vec<OxcDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut stack: Vec<(String, Span)> = Vec::new();
+    let mut in_fence = false;
+    let mut offset = 0;
+
+    for line in comment.split_inclusive('\n') {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            offset += line.len();
+            continue;
+        }
+
+        if !in_fence {
+            for token in tokenize_line(line, offset) {
+                match token {
+                    Token::Open { name, span } => stack.push((name, span)),
+                    Token::Close { name } => {
+                        if let Some(pos) = stack.iter().rposition(|(open, _)| *open == name) {
+                            stack.truncate(pos);
+                        }
+                        // A close tag with no matching open tag is ignored and produces no
+                        // diagnostic.
+                    }
+                    Token::Stray { span } => {
+                        diagnostics.push(stray_angle_bracket_diagnostic(Span::new(
+                            comment_span.start + span.start,
+                            comment_span.start + span.end,
+                        )));
+                    }
+                }
+            }
+        }
+
+        offset += line.len();
+    }
+
+    for (name, span) in stack {
+        diagnostics.push(unclosed_html_tag_diagnostic(
+            Span::new(comment_span.start + span.start, comment_span.start + span.end),
+            &name,
+        ));
+    }
+
+    diagnostics
+}
+
+fn tokenize_line(line: &str, line_offset: usize) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(rel) = line[search_from..].find('<') {
+        let start = search_from + rel;
+        let rest = &line[start..];
+        let Some(close_rel) = rest.find('>') else {
+            tokens.push(Token::Stray {
+                span: Span::new((line_offset + start) as u32, (line_offset + start + 1) as u32),
+            });
+            search_from = start + 1;
+            continue;
+        };
+
+        let inner = &rest[1..close_rel];
+        let is_close = inner.starts_with('/');
+        let name_part = inner.trim_start_matches('/').trim_end_matches('/');
+        let name = name_part.split_whitespace().next().unwrap_or("");
+
+        if name.is_empty() || !name.chars().next().is_some_and(|c| c.is_ascii_alphabetic()) {
+            tokens.push(Token::Stray {
+                span: Span::new((line_offset + start) as u32, (line_offset + start + 1) as u32),
+            });
+            search_from = start + 1;
+            continue;
+        }
+
+        let self_closing = inner.trim_end().ends_with('/') || VOID_ELEMENTS.contains(&name.to_ascii_lowercase().as_str());
+
+        if is_close {
+            tokens.push(Token::Close { name: name.to_ascii_lowercase() });
+        } else if !self_closing {
+            tokens.push(Token::Open {
+                name: name.to_ascii_lowercase(),
+                span: Span::new((line_offset + start) as u32, (line_offset + start + close_rel + 1) as u32),
+            });
+        }
+
+        search_from = start + close_rel + 1;
+    }
+
+    tokens
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("/** Returns <code>true</code> if valid. */\nexport function quux() {}", None, None),
+        ("/** A line break <br> here. */\nexport function quux() {}", None, None),
+        ("/** ```html\n<code>\n``` */\nexport function quux() {}", None, None),
+    ];
+
+    let fail = vec![
+        ("/** Returns <code>true</code if valid. */\nexport function quux() {}", None, None),
+        ("/** Stray < bracket. */\nexport function quux() {}", None, None),
+    ];
+
+    Tester::new(CheckHtmlTags::NAME, CheckHtmlTags::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,81 @@
+//! Shared helpers for discovering locally exported symbols and checking whether they carry a
+//! JSDoc block, used by [`RequirePublicDoc`](super::require_public_doc::RequirePublicDoc) and
+//! the documentation-coverage reporting built on top of it.
+
+use oxc_ast::AstKind;
+
+use crate::{AstNode, context::LintContext, module_record::{ExportEntry, ExportLocalName}};
+
+/// A locally exported symbol, resolved to its declaration node.
+pub struct ExportedSymbol<'a> {
+    pub name: &'a str,
+    pub export_span: oxc_span::Span,
+    pub symbol_id: oxc_semantic::SymbolId,
+}
+
+/// Walks `module.local_export_entries`, ignoring re-exports from other modules, resolving each
+/// name to its root binding and deduplicating by symbol id.
+pub fn local_exported_symbols<'a>(ctx: &LintContext<'a>) -> Vec<ExportedSymbol<'a>> {
+    let module = ctx.module_record();
+    let mut exported_symbols: Vec<(&str, oxc_span::Span)> = Vec::new();
+
+    for ExportEntry { module_request, local_name, span, .. } in &module.local_export_entries {
+        if module_request.is_some() {
+            continue;
+        }
+
+        match local_name {
+            ExportLocalName::Name(name_span) | ExportLocalName::Default(name_span) => {
+                exported_symbols.push((name_span.name.as_str(), *span));
+            }
+            ExportLocalName::Null => {}
+        }
+    }
+
+    let mut seen: rustc_hash::FxHashSet<oxc_semantic::SymbolId> = rustc_hash::FxHashSet::default();
+    let mut resolved = Vec::with_capacity(exported_symbols.len());
+
+    for (name, export_span) in exported_symbols {
+        let Some(symbol_id) = ctx.scoping().get_root_binding(name) else { continue };
+        if !seen.insert(symbol_id) {
+            continue;
+        }
+        resolved.push(ExportedSymbol { name, export_span, symbol_id });
+    }
+
+    resolved
+}
+
+/// Walks up ancestors from the declaration node and returns the first JSDoc block found along
+/// the way, parsed into tags. This covers common cases where docs are attached to
+/// `VariableDeclaration`, `Export*Declaration`, or the `Function`/`Class` node itself.
+pub fn attached_jsdoc<'a, 'c>(
+    start: &AstNode<'a>,
+    ctx: &'c LintContext<'a>,
+) -> Option<Vec<crate::jsdoc::JSDoc<'a, 'c>>> {
+    let mut current = start;
+    loop {
+        if let Some(jsdocs) = ctx.jsdoc().get_all_by_node(ctx.nodes(), current) {
+            return Some(jsdocs);
+        }
+
+        let parent = ctx.nodes().parent_node(current.id());
+        match parent.kind() {
+            AstKind::Program(_) => return None,
+            _ => current = parent,
+        }
+    }
+}
+
+/// Returns `true` if any JSDoc block is attached to `start`'s ancestor chain, regardless of its
+/// content. Most callers that only care about presence (not visibility tags) should use this.
+pub fn has_any_attached_jsdoc<'a>(start: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    attached_jsdoc(start, ctx).is_some()
+}
+
+/// Returns `true` if any of the attached `jsdocs`' tags match a name in `exempting_tags` (e.g.
+/// `@internal`, `@hidden`, `@ignore`), meaning the symbol was explicitly marked as not part of
+/// the public API and should be treated as documented-by-exemption.
+pub fn is_exempt_by_tag(jsdocs: &[crate::jsdoc::JSDoc], exempting_tags: &rustc_hash::FxHashSet<String>) -> bool {
+    jsdocs.iter().any(|jsdoc| jsdoc.tags().iter().any(|tag| exempting_tags.contains(tag.kind())))
+}
@@ -0,0 +1,110 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn require_linked_urls_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Bare URL in JSDoc comment.")
+        .with_help("Wrap the URL in `{@link ...}` so doc tooling renders it as a link.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireLinkedUrls;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Scans JSDoc text for raw `http://` / `https://` URLs that are not wrapped in a
+    /// `{@link}` tag.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A bare URL renders as inert text in most doc tooling; wrapping it in `{@link}` lets it
+    /// render as a clickable link instead.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /** See https://example.com for details. */
+    /// export function quux() {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /** See {@link https://example.com} for details. */
+    /// export function quux() {}
+    /// ```
+    RequireLinkedUrls,
+    jsdoc,
+    pedantic
+);
+
+impl Rule for RequireLinkedUrls {
+    fn run_once(&self, ctx: &LintContext) {
+        for node in ctx.nodes() {
+            let Some(jsdocs) = ctx.jsdoc().get_all_by_node(ctx.nodes(), node) else { continue };
+
+            for jsdoc in jsdocs {
+                let comment = jsdoc.comment();
+                for span in bare_url_spans(&comment, jsdoc.span()) {
+                    ctx.diagnostic(require_linked_urls_diagnostic(span));
+                }
+            }
+        }
+    }
+}
+
+/// Finds `http://`/`https://` URLs in `comment` that are not already inside a `{@link ...}` /
+/// `{@linkcode ...}` tag, returning their spans within the overall JSDoc comment.
+fn bare_url_spans(comment: &str, comment_span: Span) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(rel) = comment[search_from..].find("http") {
+        let start = search_from + rel;
+        let rest = &comment[start..];
+        if !(rest.starts_with("http://") || rest.starts_with("https://")) {
+            search_from = start + 4;
+            continue;
+        }
+
+        if is_inside_link_tag(comment, start) {
+            search_from = start + 4;
+            continue;
+        }
+
+        let end_rel = rest.find(|c: char| c.is_whitespace() || c == '}' || c == ')').unwrap_or(rest.len());
+        let span = Span::new(comment_span.start + start as u32, comment_span.start + (start + end_rel) as u32);
+        spans.push(span);
+        search_from = start + end_rel;
+    }
+
+    spans
+}
+
+fn is_inside_link_tag(comment: &str, offset: usize) -> bool {
+    let Some(open) = comment[..offset].rfind('{') else { return false };
+    if comment[..offset].rfind('}').is_some_and(|close| close > open) {
+        // Last `}` comes after the last `{`: we're not inside an open brace.
+        return false;
+    }
+    let tag = &comment[open + 1..offset];
+    tag.trim_start().starts_with("@link")
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("/** See {@link https://example.com} for details. */\nexport function quux() {}", None, None),
+        ("/** No urls here. */\nexport function quux() {}", None, None),
+    ];
+
+    let fail = vec![("/** See https://example.com for details. */\nexport function quux() {}", None, None)];
+
+    Tester::new(RequireLinkedUrls::NAME, RequireLinkedUrls::PLUGIN, pass, fail).test_and_snapshot();
+}
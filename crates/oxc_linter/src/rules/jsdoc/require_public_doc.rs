@@ -1,16 +1,13 @@
 use rustc_hash::FxHashSet;
+use serde::Deserialize;
 
-use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{
-    AstNode,
-    context::LintContext,
-    module_record::{ExportEntry, ExportLocalName},
-    rule::Rule,
-};
+use crate::{context::LintContext, rule::Rule};
+
+use super::doc_utils::{attached_jsdoc, is_exempt_by_tag, local_exported_symbols};
 
 fn require_public_doc_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Missing JSDoc for public export.")
@@ -19,17 +16,49 @@ fn require_public_doc_diagnostic(span: Span) -> OxcDiagnostic {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct RequirePublicDoc;
+pub struct RequirePublicDoc(Box<RequirePublicDocConfig>);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequirePublicDocConfig {
+    /// JSDoc tags that mark an export as explicitly not part of the public API, exempting it
+    /// from this rule even when it carries no description, e.g. `@internal`, `@hidden`.
+    #[serde(default = "default_exempting_tags")]
+    exempting_tags: FxHashSet<String>,
+}
+
+impl Default for RequirePublicDocConfig {
+    fn default() -> Self {
+        Self { exempting_tags: default_exempting_tags() }
+    }
+}
+
+fn default_exempting_tags() -> FxHashSet<String> {
+    ["internal", "hidden", "ignore"].into_iter().map(String::from).collect()
+}
+
+impl std::ops::Deref for RequirePublicDoc {
+    type Target = RequirePublicDocConfig;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
     ///
-    /// Requires that locally exported APIs are documented with a JSDoc block.
+    /// Requires that locally exported APIs are documented with a JSDoc block that carries a
+    /// description.
     ///
     /// This checks local exports in the current module (e.g. `export function foo() {}`,
     /// `export class Foo {}`, `export const bar = () => {}`, and `export { foo }`).
     /// Re-exports from other modules (e.g. `export { foo } from 'mod'`) are ignored.
     ///
+    /// Exports whose JSDoc carries an `@internal`, `@hidden`, or `@ignore` tag are treated as
+    /// explicitly marked non-public and are exempt, even if the block has no description of its
+    /// own. The exempting tag set is configurable, so projects that use `@private` or `@package`
+    /// conventions instead can opt those in via `exemptingTags`.
+    ///
     /// ### Why is this bad?
     ///
     /// Public APIs without documentation reduce maintainability and discoverability.
@@ -42,6 +71,8 @@ declare_oxc_lint!(
     /// export const bar = () => {};
     /// function foo() {}
     /// export { foo };
+    /// /** @param x - the thing */
+    /// export function noDescription(x) {}
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -53,6 +84,8 @@ declare_oxc_lint!(
     /// /** Docs */
     /// function foo() {}
     /// export { foo };
+    /// /** @internal */
+    /// export function notPublic() {}
     /// ```
     RequirePublicDoc,
     jsdoc,
@@ -60,63 +93,32 @@ declare_oxc_lint!(
 );
 
 impl Rule for RequirePublicDoc {
-    fn run_once(&self, ctx: &LintContext) {
-        let module = ctx.module_record();
-
-        // Collect locally exported symbol names and their export spans.
-        let mut exported_symbols: Vec<(&str, Span)> = Vec::new();
-
-        for ExportEntry { module_request, local_name, span, .. } in &module.local_export_entries {
-            // Ignore re-exports from other modules
-            if module_request.is_some() {
-                continue;
-            }
-
-            match local_name {
-                ExportLocalName::Name(name_span) | ExportLocalName::Default(name_span) => {
-                    exported_symbols.push((name_span.name.as_str(), *span));
-                }
-                ExportLocalName::Null => {
-                    // Cannot resolve anonymous default export or specifier-less cases.
-                }
-            }
-        }
-
-        // Deduplicate by symbol id once resolved
-        let mut seen: FxHashSet<oxc_semantic::SymbolId> = FxHashSet::default();
-
-        for (name, export_span) in exported_symbols {
-            let Some(symbol_id) = ctx.scoping().get_root_binding(name) else { continue };
-            if !seen.insert(symbol_id) {
-                continue;
-            }
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value
+            .get(0)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default();
+        Self(Box::new(config))
+    }
 
-            let decl_id = ctx.scoping().symbol_declaration(symbol_id);
+    fn run_once(&self, ctx: &LintContext) {
+        for symbol in local_exported_symbols(ctx) {
+            let decl_id = ctx.scoping().symbol_declaration(symbol.symbol_id);
             let decl_node = ctx.nodes().get_node(decl_id);
 
-            if !has_any_attached_jsdoc(decl_node, ctx) {
-                ctx.diagnostic(require_public_doc_diagnostic(export_span));
+            let jsdocs = attached_jsdoc(decl_node, ctx);
+            let has_description = jsdocs.as_deref().is_some_and(has_non_empty_description);
+            let is_exempt = jsdocs.as_deref().is_some_and(|jsdocs| is_exempt_by_tag(jsdocs, &self.exempting_tags));
+
+            if !has_description && !is_exempt {
+                ctx.diagnostic(require_public_doc_diagnostic(symbol.export_span));
             }
         }
     }
 }
 
-fn has_any_attached_jsdoc<'a>(start: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
-    // Walk up ancestors from the declaration node and check if any node along the way
-    // has JSDoc attached. This covers common cases where docs are attached to
-    // VariableDeclaration, Export*Declaration, or the Function/Class node itself.
-    let mut current = start;
-    loop {
-        if ctx.jsdoc().get_all_by_node(ctx.nodes(), current).is_some() {
-            return true;
-        }
-
-        let parent = ctx.nodes().parent_node(current.id());
-        match parent.kind() {
-            AstKind::Program(_) => return false,
-            _ => current = parent,
-        }
-    }
+fn has_non_empty_description(jsdocs: &[crate::jsdoc::JSDoc]) -> bool {
+    jsdocs.iter().any(|jsdoc| !jsdoc.comment().trim().is_empty())
 }
 
 #[test]
@@ -144,6 +146,26 @@ fn test() {
             None,
             None,
         ),
+        (
+            "/** @internal */\nexport function quux() {}",
+            None,
+            None,
+        ),
+        (
+            "/** @hidden */\nexport function quux() {}",
+            None,
+            None,
+        ),
+        (
+            "/** @ignore */\nexport function quux() {}",
+            None,
+            None,
+        ),
+        (
+            "/** @package */\nexport function quux() {}",
+            Some(serde_json::json!([{ "exemptingTags": ["package"] }])),
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -167,6 +189,16 @@ fn test() {
             None,
             None,
         ),
+        (
+            "/** @param x - the thing */\nexport function quux(x) {}",
+            None,
+            None,
+        ),
+        (
+            "/** @package */\nexport function quux() {}",
+            None,
+            None,
+        ),
     ];
 
     Tester::new(RequirePublicDoc::NAME, RequirePublicDoc::PLUGIN, pass, fail).test_and_snapshot();